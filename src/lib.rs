@@ -1,17 +1,17 @@
-const GET_PRODUCT_ENDPOINT: &'static str =
-    "https://api-extern.systembolaget.se/product/v1/product/";
-const GET_ALL_PRODUCTS_ENDPOINT: &'static str =
-    "https://api-extern.systembolaget.se/product/v1/product";
-const GET_PRODUCTS_WITH_STORE_ENDPOINT: &'static str =
-    "https://api-extern.systembolaget.se/product/v1/getproductswithstore";
-const SEARCH_ENDPOINT: &'static str = "https://api-extern.systembolaget.se/product/v1/search";
+const DEFAULT_BASE_URL: &'static str = "https://api-extern.systembolaget.se";
+const GET_PRODUCT_PATH: &'static str = "/product/v1/product/";
+const GET_ALL_PRODUCTS_PATH: &'static str = "/product/v1/product";
+const GET_PRODUCTS_WITH_STORE_PATH: &'static str = "/product/v1/getproductswithstore";
+const SEARCH_PATH: &'static str = "/product/v1/search";
 const API_KEY_HEADER: &'static str = "Ocp-Apim-Subscription-Key";
 
 use chrono::{Date, Utc};
+use futures::stream::{self, Stream};
 use reqwest::{header::HeaderMap, Client};
-use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize, Serializer};
 use serde_repr::{Serialize_repr, Deserialize_repr};
 use std::fmt;
+use std::time::Duration;
 
 #[derive(Debug)]
 pub enum Error {
@@ -20,9 +20,38 @@ pub enum Error {
         body: String,
     },
     Api(Vec<ApiError>),
+    /// A non-success HTTP response whose body wasn't an `ApiError`, e.g. a 429 or a
+    /// 500 with an HTML body.
+    Http {
+        status: reqwest::StatusCode,
+        body: String,
+    },
+    /// The API rejected the request with 401/403, which almost always means the
+    /// `Ocp-Apim-Subscription-Key` is missing or wrong.
+    Unauthorized,
+    /// The API key couldn't be turned into a valid `Ocp-Apim-Subscription-Key`
+    /// header value.
+    InvalidApiKey(reqwest::header::InvalidHeaderValue),
+    /// A [`SearchRequest`] failed local validation before any request was sent.
+    /// Carries every violation found, not just the first.
+    Validation(Vec<ValidationError>),
     Reqwest(reqwest::Error),
 }
 
+/// A single field-level violation found by [`SearchRequest`] validation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationError {
+    pub field: &'static str,
+    pub code: &'static str,
+    pub message: String,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ({}): {}", self.field, self.code, self.message)
+    }
+}
+
 impl From<serde_json::Error> for Error {
     fn from(err: serde_json::Error) -> Error {
         Error::Parse {
@@ -44,6 +73,12 @@ impl From<Vec<ApiError>> for Error {
     }
 }
 
+impl From<reqwest::header::InvalidHeaderValue> for Error {
+    fn from(err: reqwest::header::InvalidHeaderValue) -> Error {
+        Error::InvalidApiKey(err)
+    }
+}
+
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -53,6 +88,26 @@ impl fmt::Display for Error {
                 err, body
             ),
             Error::Reqwest(err) => write!(f, "Network error: {}", err),
+            Error::Http { status, body } => {
+                write!(f, "HTTP error: {}. Response body: '{}'", status, body)
+            }
+            Error::Unauthorized => write!(
+                f,
+                "Unauthorized: the Ocp-Apim-Subscription-Key is missing or invalid"
+            ),
+            Error::InvalidApiKey(err) => write!(f, "Invalid API key: {}", err),
+            Error::Validation(errors) => match errors.len() {
+                0 => write!(f, "Validation error: request was invalid"),
+                1 => write!(f, "Validation error: {}", errors[0]),
+                _ => {
+                    let message = errors
+                        .iter()
+                        .map(|error| format!("({})", error))
+                        .collect::<Vec<String>>()
+                        .join(", ");
+                    write!(f, "Validation errors: {}", message)
+                }
+            },
             Error::Api(errors) => match errors.len() {
                 0 => write!(
                     f,
@@ -77,7 +132,8 @@ impl std::error::Error for Error {
         match self {
             Error::Parse { err, body: _ } => Some(err),
             Error::Reqwest(err) => Some(err),
-            Error::Api(_) => None,
+            Error::InvalidApiKey(err) => Some(err),
+            Error::Api(_) | Error::Http { .. } | Error::Unauthorized | Error::Validation(_) => None,
         }
     }
 }
@@ -85,46 +141,206 @@ impl std::error::Error for Error {
 #[derive(Debug, Clone)]
 pub struct Systemet {
     client: Client,
+    base_url: String,
 }
 
 impl Systemet {
-    pub fn new(api_key: String) -> Systemet {
-        let mut headers = HeaderMap::new();
-        let key = api_key.parse().unwrap();
+    /// Builds a `Systemet` that talks to the production API using a default
+    /// `reqwest::Client`. Use [`Systemet::builder`] to point at a different host
+    /// (e.g. a mock server in tests) or to inject a custom client.
+    pub fn new(api_key: String) -> Result<Systemet, Error> {
+        Systemet::builder(api_key).build()
+    }
 
-        headers.insert(API_KEY_HEADER, key);
-        let client = Client::builder().default_headers(headers).build().unwrap();
-        Systemet { client }
+    pub fn builder(api_key: String) -> SystemetBuilder {
+        SystemetBuilder::new(api_key)
     }
 
     pub async fn get_product(&self, id: String) -> Result<Product, Error> {
-        let url = format!("{}{}", GET_PRODUCT_ENDPOINT, id);
+        let url = format!("{}{}{}", self.base_url, GET_PRODUCT_PATH, id);
         self.send_request(&url).await
     }
 
-    // TODO: Return an iterator
     pub async fn get_all_products(&self) -> Result<Vec<Product>, Error> {
-        self.send_request(GET_ALL_PRODUCTS_ENDPOINT).await
+        let url = format!("{}{}", self.base_url, GET_ALL_PRODUCTS_PATH);
+        self.send_request(&url).await
     }
 
     pub async fn get_products_with_store(&self) -> Result<Vec<ProductsWithStore>, Error> {
-        self.send_request(GET_PRODUCTS_WITH_STORE_ENDPOINT).await
+        let url = format!("{}{}", self.base_url, GET_PRODUCTS_WITH_STORE_PATH);
+        self.send_request(&url).await
     }
 
-    async fn send_request<'de, T: DeserializeOwned>(&self, url: &str) -> Result<T, Error> {
-        let body = self.client.get(url).send().await?.text().await?;
-        match serde_json::from_str::<T>(&body) {
-            Ok(product) => Ok(product),
-            // Try to parse the body as an error.
-            Err(_) => match serde_json::from_str::<Vec<ApiError>>(&body) {
-                Ok(api_error) => Err(Error::Api(api_error)),
-                Err(err) => Err(Error::Parse { err, body }),
-            },
+    pub async fn search(&self, req: SearchRequest) -> Result<SearchResponse, Error> {
+        let violations = req.validate();
+        if !violations.is_empty() {
+            return Err(Error::Validation(violations));
         }
+
+        let url = format!("{}{}", self.base_url, SEARCH_PATH);
+        self.send_request_with_query(&url, &req).await
     }
+
+    /// Lazily walks every page of `req`, yielding products one at a time instead of
+    /// buffering the whole result set. The underlying pages are fetched as the stream
+    /// is polled, so callers can `.take`/`.filter` without paying for products they
+    /// never look at.
+    pub fn product_stream(&self, req: SearchRequest) -> impl Stream<Item = Result<Product, Error>> + '_ {
+        struct State {
+            req: SearchRequest,
+            buffer: std::vec::IntoIter<Product>,
+            done: bool,
+        }
+
+        // Pin an explicit starting page instead of leaving it `None`: the API
+        // defaults to page 1 when `page` is omitted, so incrementing from
+        // `unwrap_or(0)` would re-request (and re-yield) that same first page.
+        let starting_page = req.page.unwrap_or(1);
+        let req = req.page(Some(starting_page));
+
+        let state = State {
+            req,
+            buffer: Vec::new().into_iter(),
+            done: false,
+        };
+
+        stream::unfold(state, move |mut state| async move {
+            loop {
+                if let Some(product) = state.buffer.next() {
+                    return Some((Ok(product), state));
+                }
+                if state.done {
+                    return None;
+                }
+                match self.search(state.req.clone()).await {
+                    Ok(response) => {
+                        // `metadata.total_hits` can't be relied on: it's `None`
+                        // whenever the response arrives as a bare `Vec<Product>`,
+                        // which is the shape the sibling `get_all_products`
+                        // endpoint returns. Page purely on emptiness instead.
+                        if response.products.is_empty() {
+                            state.done = true;
+                        } else {
+                            let next_page = state.req.page.unwrap_or(starting_page) + 1;
+                            state.req = state.req.clone().page(Some(next_page));
+                        }
+                        state.buffer = response.products.into_iter();
+                    }
+                    Err(err) => {
+                        state.done = true;
+                        return Some((Err(err), state));
+                    }
+                }
+            }
+        })
+    }
+
+    /// Convenience wrapper around [`Systemet::product_stream`] that walks the entire
+    /// catalog with no search filters applied.
+    pub fn all_products_stream(&self) -> impl Stream<Item = Result<Product, Error>> + '_ {
+        self.product_stream(SearchRequest::new())
+    }
+
+    async fn send_request<T: DeserializeOwned>(&self, url: &str) -> Result<T, Error> {
+        self.send_request_with_query(url, &()).await
+    }
+
+    async fn send_request_with_query<T: DeserializeOwned, Q: Serialize + ?Sized>(
+        &self,
+        url: &str,
+        query: &Q,
+    ) -> Result<T, Error> {
+        let response = self.client.get(url).query(query).send().await?;
+        let status = response.status();
+
+        if status == reqwest::StatusCode::UNAUTHORIZED || status == reqwest::StatusCode::FORBIDDEN
+        {
+            return Err(Error::Unauthorized);
+        }
+
+        let body = response.text().await?;
+
+        if !status.is_success() {
+            return Err(match serde_json::from_str::<Vec<ApiError>>(&body) {
+                Ok(errors) => Error::Api(errors),
+                Err(_) => match serde_json::from_str::<ApiError>(&body) {
+                    Ok(error) => Error::Api(vec![error]),
+                    Err(_) => Error::Http { status, body },
+                },
+            });
+        }
+
+        serde_json::from_str::<T>(&body).map_err(|err| Error::Parse { err, body })
+    }
+}
+
+/// Builds a [`Systemet`], letting tests point it at a mock server and production
+/// code tune the underlying `reqwest::Client`.
+#[derive(Debug, Clone)]
+pub struct SystemetBuilder {
+    api_key: String,
+    base_url: String,
+    client: Option<Client>,
+    timeout: Option<Duration>,
 }
 
-#[derive(Serialize_repr, Deserialize_repr, Debug, PartialEq)]
+impl SystemetBuilder {
+    fn new(api_key: String) -> SystemetBuilder {
+        SystemetBuilder {
+            api_key,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            client: None,
+            timeout: None,
+        }
+    }
+
+    /// Overrides the host the client talks to. Defaults to the production
+    /// `api-extern.systembolaget.se`.
+    pub fn base_url(mut self, base_url: String) -> SystemetBuilder {
+        self.base_url = base_url;
+        self
+    }
+
+    /// Uses a caller-provided `reqwest::Client` instead of building one from the
+    /// API key. The caller is responsible for setting any headers it needs.
+    pub fn client(mut self, client: Client) -> SystemetBuilder {
+        self.client = Some(client);
+        self
+    }
+
+    /// Sets a request timeout on the client this builder constructs. Has no effect
+    /// if a custom [`SystemetBuilder::client`] is supplied.
+    pub fn timeout(mut self, timeout: Duration) -> SystemetBuilder {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn build(self) -> Result<Systemet, Error> {
+        let client = match self.client {
+            Some(client) => client,
+            None => {
+                let mut headers = HeaderMap::new();
+                headers.insert(API_KEY_HEADER, self.api_key.parse()?);
+
+                let mut builder = Client::builder().default_headers(headers);
+                if let Some(timeout) = self.timeout {
+                    builder = builder.timeout(timeout);
+                }
+                builder.build()?
+            }
+        };
+
+        Ok(Systemet {
+            client,
+            // Trim any trailing slash so endpoint paths (which start with `/`)
+            // don't produce a double slash when joined onto a base URL like
+            // `https://mock.test/`.
+            base_url: self.base_url.trim_end_matches('/').to_string(),
+        })
+    }
+}
+
+#[derive(Serialize_repr, Deserialize_repr, Debug, Clone, PartialEq)]
 #[repr(i32)]
 pub enum SortDirection {
     Ascending = 0,
@@ -141,7 +357,7 @@ pub enum SortDirection {
 // }
 
 
-#[derive(Serialize_repr, Deserialize_repr, Debug, PartialEq)]
+#[derive(Serialize_repr, Deserialize_repr, Debug, Clone, PartialEq)]
 #[repr(i32)]
 pub enum SortKey {
     Price = 0,
@@ -174,8 +390,54 @@ impl SearchRequest {
         SearchRequest::default()
     }
 
-    fn validate(&self) -> bool {
-        self != &Self::default()
+    /// Checks the request for violations the API would otherwise reject with an
+    /// opaque server error, e.g. `price_min` above `price_max`. Returns every
+    /// violation found rather than stopping at the first one.
+    fn validate(&self) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+
+        if let (Some(min), Some(max)) = (self.price_min, self.price_max) {
+            if min > max {
+                errors.push(ValidationError {
+                    field: "price_min",
+                    code: "invalid_search_price_range",
+                    message: format!(
+                        "price_min ({}) must not be greater than price_max ({})",
+                        min, max
+                    ),
+                });
+            }
+        }
+
+        for (field, value) in [
+            ("alcohol_percentage_min", self.alcohol_percentage_min),
+            ("alcohol_percentage_max", self.alcohol_percentage_max),
+        ] {
+            if let Some(value) = value {
+                if !(0.0..=100.0).contains(&value) {
+                    errors.push(ValidationError {
+                        field,
+                        code: "invalid_search_alcohol_percentage",
+                        message: format!(
+                            "{} must be between 0 and 100, got {}",
+                            field, value
+                        ),
+                    });
+                }
+            }
+        }
+
+        if let Some(page) = self.page {
+            if page < 0 {
+                errors.push(ValidationError {
+                    field: "page",
+                    code: "invalid_search_page",
+                    message: format!("page must not be negative, got {}", page),
+                });
+            }
+        }
+
+        errors
     }
 
     pub fn alcohol_percentage_max(mut self, alcohol_percentage_max: Option<f64>) -> SearchRequest {
@@ -188,7 +450,7 @@ impl SearchRequest {
         self
     }
 
-    pub fn assortment_text(mut self, assortment_text: Option<String>) -> SearchRequest {
+    pub fn assortment_text(mut self, assortment_text: Option<Assortment>) -> SearchRequest {
         self.assortment_text = assortment_text;
         self
     }
@@ -209,7 +471,7 @@ impl SearchRequest {
     }
 
     /// This property is called "type" in the API) -> SearchRequest { } but that's a keyword in Rust.
-    pub fn kind(mut self, kind: Option<String>) -> SearchRequest {
+    pub fn kind(mut self, kind: Option<Kind>) -> SearchRequest {
         self.kind = kind;
         self
     }
@@ -249,7 +511,7 @@ impl SearchRequest {
         self
     }
 
-    pub fn seal(mut self, seal: Option<String>) -> SearchRequest {
+    pub fn seal(mut self, seal: Option<Seal>) -> SearchRequest {
         self.seal = seal;
         self
     }
@@ -284,7 +546,7 @@ impl SearchRequest {
         self
     }
 
-    pub fn sub_category(mut self, sub_category: Option<String>) -> SearchRequest {
+    pub fn sub_category(mut self, sub_category: Option<SubCategory>) -> SearchRequest {
         self.sub_category = sub_category;
         self
     }
@@ -295,49 +557,139 @@ impl SearchRequest {
     }
 }
 
-#[derive(Default, Serialize, Debug, PartialEq)]
+#[derive(Default, Clone, Serialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
 pub struct SearchRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub alcohol_percentage_max: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub alcohol_percentage_min: Option<f64>,
-    pub assortment_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assortment_text: Option<Assortment>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub bottle_type_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub country: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub csr: Option<String>,
     /// This property is called "type" in the API, but that's a keyword in Rust.
-    pub kind: Option<String>,
+    #[serde(rename = "Type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<Kind>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub news: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub origin_level_1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub origin_level_2: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub other_selections: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub page: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub price_max: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub price_min: Option<f64>,
-    pub seal: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seal: Option<Seal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub search_query: Option<String>,
     #[serde(with = "option_date_serializer")]
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub sell_start_date_from: Option<Date<Utc>>,
     #[serde(with = "option_date_serializer")]
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub sell_start_date_to: Option<Date<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub sort_by: Option<SortKey>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub sort_direction: Option<SortDirection>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub style: Option<String>,
-    pub sub_category: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sub_category: Option<SubCategory>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub vintage: Option<String>,
 }
 
+/// A single page of results from [`Systemet::search`].
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct SearchResponse {
+    pub products: Vec<Product>,
+    pub metadata: SearchResponseMetadata,
+}
+
+/// Pagination metadata accompanying a [`SearchResponse`].
+///
+/// `None` means the real value is unknown, e.g. because the response body
+/// didn't carry the enveloped shape this crate expects and the metadata had to
+/// be synthesized from a bare `Vec<Product>` page. Synthesized metadata has
+/// every field set to `None`.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct SearchResponseMetadata {
+    pub total_hits: Option<i32>,
+    pub page: Option<i32>,
+    pub page_size: Option<i32>,
+}
+
+// The enveloped `{ Products, Metadata }` shape hasn't been confirmed against a
+// real response body, so fall back to treating the body as a bare `Vec<Product>`
+// (with synthesized metadata) if it doesn't match, rather than failing every
+// `search` call with `Error::Parse` on a schema mismatch.
+impl<'de> Deserialize<'de> for SearchResponse {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "PascalCase")]
+        struct Enveloped {
+            products: Vec<Product>,
+            metadata: SearchResponseMetadata,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Shape {
+            Enveloped(Enveloped),
+            Bare(Vec<Product>),
+        }
+
+        Ok(match Shape::deserialize(deserializer)? {
+            Shape::Enveloped(enveloped) => SearchResponse {
+                products: enveloped.products,
+                metadata: enveloped.metadata,
+            },
+            Shape::Bare(products) => SearchResponse {
+                // The real totals aren't known when the body wasn't enveloped,
+                // so leave them `None` rather than handing back the page length
+                // disguised as the catalog-wide total.
+                metadata: SearchResponseMetadata {
+                    total_hits: None,
+                    page: None,
+                    page_size: None,
+                },
+                products,
+            },
+        })
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "PascalCase")]
 pub struct Product {
     pub alcohol_percentage: f64,
-    pub assortment: Option<String>,
+    pub assortment: Option<Assortment>,
     pub assortment_text: Option<String>,
     pub beverage_description_short: Option<String>,
     pub bottle_text_short: Option<String>,
-    pub category: Option<String>,
+    pub category: Option<Category>,
     pub country: Option<String>,
-    pub ethical_label: Option<String>,
+    pub ethical_label: Option<EthicalLabel>,
     pub is_completely_out_of_stock: bool,
     pub is_ethical: bool,
     pub is_in_store_search_assortment: Option<String>,
@@ -350,7 +702,7 @@ pub struct Product {
     pub is_web_launch: bool,
     /// This property is called "type" in the API, but that's is a keyword in Rust.
     #[serde(rename = "Type")]
-    pub kind: Option<String>,
+    pub kind: Option<Kind>,
     pub origin_level_1: Option<String>,
     pub origin_level_2: Option<String>,
     pub price: f64,
@@ -362,11 +714,11 @@ pub struct Product {
     pub product_number: String,
     pub recycle_fee: f64,
     pub restricted_parcel_quantity: i32,
-    pub seal: Option<String>,
+    pub seal: Option<Seal>,
     #[serde(with = "date_serializer")]
     pub sell_start_date: Date<Utc>,
     pub style: Option<String>,
-    pub sub_category: Option<String>,
+    pub sub_category: Option<SubCategory>,
     pub supplier_name: Option<String>,
     pub taste: Option<String>,
     pub usage: Option<String>,
@@ -459,3 +811,111 @@ mod option_date_serializer {
             .map_err(serde::de::Error::custom)
     }
 }
+
+/// Helper for defining a string-backed enum that mirrors one of Systembolaget's
+/// Swedish enumeration fields. Unknown values deserialize into `Other` instead of
+/// failing, so the crate keeps working when the API adds a new value.
+macro_rules! string_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $($variant:ident => $value:expr,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub enum $name {
+            $($variant,)*
+            /// A value this crate doesn't know about yet. Carrying the raw string
+            /// lets it round-trip instead of failing to deserialize.
+            Other(String),
+        }
+
+        impl Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                let s = match self {
+                    $($name::$variant => $value,)*
+                    $name::Other(s) => s.as_str(),
+                };
+                serializer.serialize_str(s)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let s = String::deserialize(deserializer)?;
+                Ok(match s.as_str() {
+                    $($value => $name::$variant,)*
+                    _ => $name::Other(s),
+                })
+            }
+        }
+    };
+}
+
+string_enum! {
+    /// The `Category` field on [`Product`].
+    pub enum Category {
+        Beer => "Öl",
+        Wine => "Vin",
+        Spirits => "Sprit",
+        CiderAndMixedDrinks => "Cider, blanddryck m.m.",
+    }
+}
+
+string_enum! {
+    /// The `Assortment` field on [`Product`] and the `AssortmentText` filter on
+    /// [`SearchRequest`].
+    pub enum Assortment {
+        FixedAssortment => "Fast sortiment",
+        TemporaryAssortment => "Tillfälligt sortiment",
+        OrderAssortment => "Beställningssortiment",
+        LocalAndSmallScale => "Lokalt & Småskaligt sortiment",
+    }
+}
+
+string_enum! {
+    /// The `SubCategory` field on [`Product`].
+    pub enum SubCategory {
+        RedWine => "Rött vin",
+        WhiteWine => "Vitt vin",
+        RoseWine => "Rosévin",
+        SparklingWine => "Mousserande vin",
+    }
+}
+
+string_enum! {
+    /// The `Seal` field on [`Product`], e.g. organic or fair-trade certifications.
+    pub enum Seal {
+        Organic => "Ekologisk",
+        FairTrade => "Rättvisemärkt",
+        Kosher => "Kosher",
+    }
+}
+
+string_enum! {
+    /// The `Type` field on [`Product`]. Called `Kind` here since `type` is a
+    /// keyword in Rust.
+    pub enum Kind {
+        Wine => "Vin",
+        Beer => "Öl",
+        Spirits => "Sprit",
+        CiderAndMixedDrinks => "Cider, blanddryck m.m.",
+        AlcoholFree => "Alkoholfritt",
+    }
+}
+
+string_enum! {
+    /// The `EthicalLabel` field on [`Product`].
+    pub enum EthicalLabel {
+        Organic => "Ekologisk",
+        FairTrade => "Rättvisemärkt",
+        OrganicAndFairTrade => "Ekologisk och Rättvisemärkt",
+    }
+}